@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use serde_json::json;
+use tracing::Span;
 use limit_core::{Session, BackendRunner, RunnerOutput};
 use limit_sarscov2::{MultiIntentQuestion, QueryPlan, SarsCov2Graph, VirusNode, VirologyNode};
 
@@ -9,6 +10,10 @@ pub struct SarsAgent;
 impl BackendRunner for SarsAgent {
     fn kind(&self) -> limit_core::runners::RunnerKind { limit_core::runners::RunnerKind::Rust }
 
+    #[tracing::instrument(
+        skip_all,
+        fields(plan_id = tracing::field::Empty, plan_steps = tracing::field::Empty, virology_nodes = tracing::field::Empty)
+    )]
     async fn run(&self, task: serde_json::Value) -> anyhow::Result<RunnerOutput> {
         // Parse multi-intent question
         let q: MultiIntentQuestion = serde_json::from_value(task)?;
@@ -17,6 +22,7 @@ impl BackendRunner for SarsAgent {
             description: "Decompose into virology + genomics intents".into(),
             steps: vec!["retrieve spike-ACE2 evidence".into(), "list variant mutations".into()],
         };
+        Span::current().record("plan_id", tracing::field::display(plan.id));
 
         // Build a minimal graph
         let mut graph = SarsCov2Graph::new(VirusNode { id: uuid::Uuid::new_v4(), name: "SARS-CoV-2".into(), genome_kb: 30.0 });
@@ -26,6 +32,9 @@ impl BackendRunner for SarsAgent {
             details: "Key residue interactions implicated in entry".into(),
         });
 
+        Span::current().record("plan_steps", plan.steps.len());
+        Span::current().record("virology_nodes", graph.virology.len());
+
         Ok(RunnerOutput {
             ok: true,
             stdout: "SARS-CoV-2 graph initialized".into(),
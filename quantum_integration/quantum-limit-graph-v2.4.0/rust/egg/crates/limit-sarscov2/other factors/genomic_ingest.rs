@@ -0,0 +1,77 @@
+//! Parsing for real SARS-CoV-2 variant evidence (VCF spike mutations and
+//! FASTA reference accessions), used to materialize `Genomic` nodes instead
+//! of fabricated placeholders.
+
+/// A single reported spike mutation, e.g. `S:N501Y`, with its supporting
+/// accession and effect size.
+#[derive(Debug, Clone)]
+pub struct VcfVariant {
+    pub gene: String,
+    pub protein_change: String,
+    pub accession: String,
+    pub effect_size: f32,
+}
+
+impl VcfVariant {
+    /// The mutation label used as the node's `label`, e.g. `S:N501Y`.
+    pub fn mutation_label(&self) -> String {
+        format!("{}:{}", self.gene, self.protein_change)
+    }
+}
+
+/// Parse a minimal VCF body into `VcfVariant`s. Expects the `INFO` column to
+/// carry `GENE=`, `AA=` (protein change, e.g. `N501Y`), and optionally
+/// `EFFECT=` (0.0-1.0 effect size, defaulting to 0.5 when absent). Records
+/// without an `AA` entry are skipped since they carry no mutation label.
+pub fn parse_vcf(content: &str) -> Vec<VcfVariant> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 8 {
+                return None;
+            }
+
+            let id = fields[2];
+            let info = fields[7];
+
+            let mut gene = None;
+            let mut protein_change = None;
+            let mut effect_size = 0.5_f32;
+
+            for entry in info.split(';') {
+                if let Some((key, value)) = entry.split_once('=') {
+                    match key {
+                        "GENE" => gene = Some(value.to_string()),
+                        "AA" => protein_change = Some(value.to_string()),
+                        "EFFECT" => effect_size = value.parse().unwrap_or(0.5),
+                        _ => {}
+                    }
+                }
+            }
+
+            Some(VcfVariant {
+                gene: gene.unwrap_or_else(|| "S".to_string()),
+                protein_change: protein_change?,
+                accession: if id == "." {
+                    format!("{}:{}", fields[0], fields[1])
+                } else {
+                    id.to_string()
+                },
+                effect_size,
+            })
+        })
+        .collect()
+}
+
+/// Extract accession IDs (the first whitespace-delimited token of each
+/// header) from a FASTA reference, for use as additional supporting sources.
+pub fn parse_fasta_accessions(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter(|line| line.starts_with('>'))
+        .filter_map(|line| line.trim_start_matches('>').split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
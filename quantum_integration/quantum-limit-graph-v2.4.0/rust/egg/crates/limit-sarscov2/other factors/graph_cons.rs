@@ -1,11 +1,13 @@
 use petgraph::graph::{DiGraph, NodeIndex};
 use std::collections::HashMap;
 use crate::core::*;
+use crate::genomic_ingest::VcfVariant;
 
 pub struct QuantumLimitGraphBuilder {
     graph: DiGraph<Node, Edge>,
     node_map: HashMap<String, NodeIndex>,
     node_counter: usize,
+    genomic_variants: Vec<VcfVariant>,
 }
 
 impl QuantumLimitGraphBuilder {
@@ -14,9 +16,18 @@ impl QuantumLimitGraphBuilder {
             graph: DiGraph::new(),
             node_map: HashMap::new(),
             node_counter: 0,
+            genomic_variants: Vec::new(),
         }
     }
 
+    /// Supply real variant evidence (e.g. parsed from a VCF) to materialize
+    /// as `Genomic` nodes when the graph is built, instead of the fabricated
+    /// `GenomicVariants` placeholder node.
+    pub fn with_genomic_variants(mut self, variants: Vec<VcfVariant>) -> Self {
+        self.genomic_variants = variants;
+        self
+    }
+
     fn add_node_with_key(&mut self, key: String, node: Node) -> NodeIndex {
         let idx = self.graph.add_node(node);
         self.node_map.insert(key, idx);
@@ -24,7 +35,12 @@ impl QuantumLimitGraphBuilder {
         idx
     }
 
+    #[tracing::instrument(skip_all, name = "build_quantum_limit_graph")]
     pub fn build_quantum_limit_graph(mut self) -> DiGraph<Node, Edge> {
+        let metrics = crate::telemetry::GraphMetrics::new();
+        let mut stage_start = std::time::Instant::now();
+        let mut stage_node_start = self.node_counter;
+
         // Stage 1: Central Node
         let quantum_limit = self.add_node_with_key(
             "QuantumLimitGraph".to_string(),
@@ -75,6 +91,10 @@ impl QuantumLimitGraphBuilder {
             .with_quantum_weight(0.88),
         );
 
+        metrics.record_stage_nodes(&CorrelationStage::Stage1Direct, self.node_counter - stage_node_start, stage_start.elapsed());
+        stage_start = std::time::Instant::now();
+        stage_node_start = self.node_counter;
+
         // Stage 2: Indirect Factors - Comorbidities
         let diabetes = self.add_node_with_key(
             "Diabetes".to_string(),
@@ -149,6 +169,10 @@ impl QuantumLimitGraphBuilder {
             .with_quantum_weight(0.72),
         );
 
+        metrics.record_stage_nodes(&CorrelationStage::Stage2Indirect, self.node_counter - stage_node_start, stage_start.elapsed());
+        stage_start = std::time::Instant::now();
+        stage_node_start = self.node_counter;
+
         // Stage 3: Systemic Socioeconomic Factors
         let crowded_housing = self.add_node_with_key(
             "CrowdedHousing".to_string(),
@@ -186,6 +210,10 @@ impl QuantumLimitGraphBuilder {
             .with_quantum_weight(0.86),
         );
 
+        metrics.record_stage_nodes(&CorrelationStage::Stage3Systemic, self.node_counter - stage_node_start, stage_start.elapsed());
+        stage_start = std::time::Instant::now();
+        stage_node_start = self.node_counter;
+
         // Stage 4: Environmental Factors
         let air_quality = self.add_node_with_key(
             "AirQuality".to_string(),
@@ -223,6 +251,10 @@ impl QuantumLimitGraphBuilder {
             .with_quantum_weight(0.65),
         );
 
+        metrics.record_stage_nodes(&CorrelationStage::Stage4Environmental, self.node_counter - stage_node_start, stage_start.elapsed());
+        stage_start = std::time::Instant::now();
+        stage_node_start = self.node_counter;
+
         // Stage 5: Quantum Factors
         let immune_response = self.add_node_with_key(
             "ImmuneResponse".to_string(),
@@ -236,17 +268,28 @@ impl QuantumLimitGraphBuilder {
             .with_quantum_weight(0.91),
         );
 
-        let genomic_variants = self.add_node_with_key(
-            "GenomicVariants".to_string(),
-            Node::new(
-                17,
-                "Genomic Variants".to_string(),
-                NodeType::Genomic,
-                CorrelationStage::Stage5Quantum,
-            )
-            .with_description("Viral mutations with quantum correlation patterns".to_string())
-            .with_quantum_weight(0.89),
-        );
+        // Only fabricate the generic placeholder when no real variant
+        // evidence was supplied; otherwise `ingest_genomic_variants` below
+        // materializes one node per reported mutation instead.
+        let genomic_variants = if self.genomic_variants.is_empty() {
+            Some(self.add_node_with_key(
+                "GenomicVariants".to_string(),
+                Node::new(
+                    17,
+                    "Genomic Variants".to_string(),
+                    NodeType::Genomic,
+                    CorrelationStage::Stage5Quantum,
+                )
+                .with_description("Viral mutations with quantum correlation patterns".to_string())
+                .with_quantum_weight(0.89),
+            ))
+        } else {
+            None
+        };
+
+        self.ingest_genomic_variants(quantum_limit);
+
+        metrics.record_stage_nodes(&CorrelationStage::Stage5Quantum, self.node_counter - stage_node_start, stage_start.elapsed());
 
         // Add edges with quantum entanglement
         self.add_quantum_edges(quantum_limit, ace2, spike_protein, viral_load,
@@ -255,9 +298,70 @@ impl QuantumLimitGraphBuilder {
                                healthcare_access, air_quality, ventilation,
                                temperature_humidity, immune_response, genomic_variants);
 
+        for stage in [
+            CorrelationStage::Stage1Direct,
+            CorrelationStage::Stage2Indirect,
+            CorrelationStage::Stage3Systemic,
+            CorrelationStage::Stage4Environmental,
+            CorrelationStage::Stage5Quantum,
+        ] {
+            let count = self
+                .graph
+                .edge_weights()
+                .filter(|edge| format!("{:?}", edge.stage) == format!("{stage:?}"))
+                .count();
+            metrics.record_stage_edges(&stage, count);
+        }
+
         self.graph
     }
 
+    /// Materialize one `Genomic` node per distinct reported mutation from
+    /// `self.genomic_variants`, linking each to `central` with a
+    /// `CorrelationType::Causal` edge weighted by the variant's effect size.
+    fn ingest_genomic_variants(&mut self, central: NodeIndex) {
+        let central_numeric_id = self.graph[central].numeric_id;
+
+        let mut grouped: HashMap<String, (String, Vec<String>, f32, usize)> = HashMap::new();
+        for variant in &self.genomic_variants {
+            let entry = grouped
+                .entry(variant.mutation_label())
+                .or_insert_with(|| (variant.gene.clone(), Vec::new(), 0.0, 0));
+            entry.1.push(variant.accession.clone());
+            entry.2 += variant.effect_size;
+            entry.3 += 1;
+        }
+
+        let mut labels: Vec<String> = grouped.keys().cloned().collect();
+        labels.sort();
+
+        for label in labels {
+            let (gene, sources, effect_sum, evidence_count) = grouped.remove(&label).unwrap();
+            let avg_effect = (effect_sum / evidence_count as f32).clamp(0.0, 1.0);
+
+            let numeric_id = self.node_counter;
+            let mut node = Node::new(numeric_id, label.clone(), NodeType::Genomic, CorrelationStage::Stage5Quantum)
+                .with_description(format!(
+                    "{} mutation in {}, reported across {} supporting record(s)",
+                    label, gene, evidence_count
+                ))
+                .with_quantum_weight(avg_effect);
+            node.metadata.sources = sources;
+            node.metadata.evidence_count = evidence_count;
+            node.metadata.confidence_score = avg_effect;
+
+            let idx = self.add_node_with_key(format!("Variant::{label}"), node);
+
+            self.graph.add_edge(
+                central,
+                idx,
+                Edge::new(central_numeric_id, numeric_id, avg_effect, CorrelationType::Causal, CorrelationStage::Stage5Quantum)
+                    .with_description(format!("{label} mutation causally linked to the central virus node"))
+                    .with_quantum_entanglement(avg_effect * 0.9),
+            );
+        }
+    }
+
     fn add_quantum_edges(
         &mut self,
         quantum_limit: NodeIndex,
@@ -277,7 +381,7 @@ impl QuantumLimitGraphBuilder {
         ventilation: NodeIndex,
         temperature_humidity: NodeIndex,
         immune_response: NodeIndex,
-        genomic_variants: NodeIndex,
+        genomic_variants: Option<NodeIndex>,
     ) {
         // Stage 1: Direct biological connections
         self.graph.add_edge(
@@ -429,24 +533,38 @@ impl QuantumLimitGraphBuilder {
                 .with_quantum_entanglement(0.92),
         );
 
-        self.graph.add_edge(
-            genomic_variants,
-            spike_protein,
-            Edge::new(17, 2, 0.94, CorrelationType::QuantumEntangled, CorrelationStage::Stage5Quantum)
-                .with_description("Mutations alter spike protein structure".to_string())
-                .with_quantum_entanglement(0.93),
-        );
-
-        self.graph.add_edge(
-            genomic_variants,
-            immune_response,
-            Edge::new(17, 16, 0.87, CorrelationType::QuantumEntangled, CorrelationStage::Stage5Quantum)
-                .with_description("Variants evade immune recognition".to_string())
-                .with_quantum_entanglement(0.88),
-        );
+        // Only wired when the generic placeholder node above was created;
+        // ingested per-mutation nodes get their own edges in
+        // `ingest_genomic_variants` instead.
+        if let Some(genomic_variants) = genomic_variants {
+            self.graph.add_edge(
+                genomic_variants,
+                spike_protein,
+                Edge::new(17, 2, 0.94, CorrelationType::QuantumEntangled, CorrelationStage::Stage5Quantum)
+                    .with_description("Mutations alter spike protein structure".to_string())
+                    .with_quantum_entanglement(0.93),
+            );
+
+            self.graph.add_edge(
+                genomic_variants,
+                immune_response,
+                Edge::new(17, 16, 0.87, CorrelationType::QuantumEntangled, CorrelationStage::Stage5Quantum)
+                    .with_description("Variants evade immune recognition".to_string())
+                    .with_quantum_entanglement(0.88),
+            );
+        }
     }
 }
 
 pub fn build_quantum_limit_graph() -> DiGraph<Node, Edge> {
     QuantumLimitGraphBuilder::new().build_quantum_limit_graph()
 }
+
+/// Build the graph with `Genomic` nodes ingested from a VCF body of spike
+/// mutations, in place of the fabricated `GenomicVariants` placeholder.
+pub fn build_quantum_limit_graph_with_variants(vcf_content: &str) -> DiGraph<Node, Edge> {
+    let variants = crate::genomic_ingest::parse_vcf(vcf_content);
+    QuantumLimitGraphBuilder::new()
+        .with_genomic_variants(variants)
+        .build_quantum_limit_graph()
+}
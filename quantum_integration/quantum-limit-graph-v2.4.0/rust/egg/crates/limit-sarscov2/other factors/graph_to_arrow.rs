@@ -0,0 +1,159 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow::array::{Float32Array, ListBuilder, StringBuilder, StringDictionaryBuilder, UInt32Array};
+use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use petgraph::graph::DiGraph;
+
+use crate::core::*;
+
+/// Arrow schema for the node record batch.
+pub fn node_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("numeric_id", DataType::UInt32, false),
+        Field::new("label", DataType::Utf8, false),
+        Field::new(
+            "node_type",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new(
+            "stage",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new("quantum_weight", DataType::Float32, false),
+        Field::new("confidence_score", DataType::Float32, false),
+        Field::new("evidence_count", DataType::UInt32, false),
+        Field::new(
+            "sources",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+    ])
+}
+
+/// Arrow schema for the edge record batch.
+pub fn edge_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("source", DataType::UInt32, false),
+        Field::new("target", DataType::UInt32, false),
+        Field::new("correlation_strength", DataType::Float32, false),
+        Field::new("quantum_entanglement", DataType::Float32, false),
+        Field::new(
+            "correlation_type",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+        Field::new(
+            "stage",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            false,
+        ),
+    ])
+}
+
+/// Convert the graph's nodes into a single Arrow `RecordBatch`.
+pub fn nodes_to_record_batch(graph: &DiGraph<Node, Edge>) -> RecordBatch {
+    let mut numeric_id = Vec::new();
+    let mut label = StringBuilder::new();
+    let mut node_type = StringDictionaryBuilder::<Int32Type>::new();
+    let mut stage = StringDictionaryBuilder::<Int32Type>::new();
+    let mut quantum_weight = Vec::new();
+    let mut confidence_score = Vec::new();
+    let mut evidence_count = Vec::new();
+    let mut sources = ListBuilder::new(StringBuilder::new());
+
+    for node_idx in graph.node_indices() {
+        let node = &graph[node_idx];
+        numeric_id.push(node.numeric_id as u32);
+        label.append_value(&node.label);
+        node_type.append_value(format!("{:?}", node.node_type));
+        stage.append_value(format!("{:?}", node.stage));
+        quantum_weight.push(node.quantum_weight);
+        confidence_score.push(node.metadata.confidence_score);
+        evidence_count.push(node.metadata.evidence_count as u32);
+        for source in &node.metadata.sources {
+            sources.values().append_value(source);
+        }
+        sources.append(true);
+    }
+
+    RecordBatch::try_new(
+        Arc::new(node_schema()),
+        vec![
+            Arc::new(UInt32Array::from(numeric_id)),
+            Arc::new(label.finish()),
+            Arc::new(node_type.finish()),
+            Arc::new(stage.finish()),
+            Arc::new(Float32Array::from(quantum_weight)),
+            Arc::new(Float32Array::from(confidence_score)),
+            Arc::new(UInt32Array::from(evidence_count)),
+            Arc::new(sources.finish()),
+        ],
+    )
+    .expect("node columns must match node_schema")
+}
+
+/// Convert the graph's edges into a single Arrow `RecordBatch`.
+pub fn edges_to_record_batch(graph: &DiGraph<Node, Edge>) -> RecordBatch {
+    let mut source = Vec::new();
+    let mut target = Vec::new();
+    let mut correlation_strength = Vec::new();
+    let mut quantum_entanglement = Vec::new();
+    let mut correlation_type = StringDictionaryBuilder::<Int32Type>::new();
+    let mut stage = StringDictionaryBuilder::<Int32Type>::new();
+
+    for edge_idx in graph.edge_indices() {
+        let edge = &graph[edge_idx];
+        source.push(edge.from as u32);
+        target.push(edge.to as u32);
+        correlation_strength.push(edge.correlation_strength);
+        quantum_entanglement.push(edge.quantum_entanglement);
+        correlation_type.append_value(format!("{:?}", edge.correlation_type));
+        stage.append_value(format!("{:?}", edge.stage));
+    }
+
+    RecordBatch::try_new(
+        Arc::new(edge_schema()),
+        vec![
+            Arc::new(UInt32Array::from(source)),
+            Arc::new(UInt32Array::from(target)),
+            Arc::new(Float32Array::from(correlation_strength)),
+            Arc::new(Float32Array::from(quantum_entanglement)),
+            Arc::new(correlation_type.finish()),
+            Arc::new(stage.finish()),
+        ],
+    )
+    .expect("edge columns must match edge_schema")
+}
+
+/// Build the node and edge record batches for the graph, ready for
+/// downstream analytics in pandas, Polars, or DuckDB.
+pub fn export_graph_to_arrow(graph: &DiGraph<Node, Edge>) -> (RecordBatch, RecordBatch) {
+    (nodes_to_record_batch(graph), edges_to_record_batch(graph))
+}
+
+/// Write the graph to a pair of Parquet files, `<base_path>.nodes.parquet`
+/// and `<base_path>.edges.parquet`.
+pub fn export_graph_to_parquet(graph: &DiGraph<Node, Edge>, base_path: &str) -> Result<(), ParquetError> {
+    let (nodes, edges) = export_graph_to_arrow(graph);
+
+    write_record_batch(&nodes, &format!("{base_path}.nodes.parquet"))?;
+    write_record_batch(&edges, &format!("{base_path}.edges.parquet"))?;
+
+    Ok(())
+}
+
+fn write_record_batch(batch: &RecordBatch, path: &str) -> Result<(), ParquetError> {
+    let file = File::create(path).map_err(|e| ParquetError::General(e.to_string()))?;
+    let props = WriterProperties::builder().build();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
+}
@@ -3,7 +3,7 @@ use crate::core::*;
 use crate::serial::*;
 use std::collections::HashSet;
 
-pub fn export_graph_to_json(graph: &DiGraph<Node, Edge>) -> String {
+fn build_serializable_graph(graph: &DiGraph<Node, Edge>) -> SerializableGraph {
     let mut nodes = Vec::new();
     let mut edges = Vec::new();
     let mut stages = HashSet::new();
@@ -32,7 +32,7 @@ pub fn export_graph_to_json(graph: &DiGraph<Node, Edge>) -> String {
         0.0
     };
 
-    let serializable_graph = SerializableGraph {
+    SerializableGraph {
         nodes,
         edges,
         metadata: GraphMetadata {
@@ -41,12 +41,27 @@ pub fn export_graph_to_json(graph: &DiGraph<Node, Edge>) -> String {
             stages: stages.into_iter().collect(),
             quantum_correlation_average: quantum_avg,
             generated_at: chrono::Utc::now().to_rfc3339(),
+            signature: None,
+            signer_pubkey: None,
         },
-    };
+    }
+}
+
+#[tracing::instrument(skip_all, name = "export_graph_to_json")]
+pub fn export_graph_to_json(graph: &DiGraph<Node, Edge>) -> String {
+    serde_json::to_string_pretty(&build_serializable_graph(graph)).unwrap()
+}
 
+/// Like `export_graph_to_json`, but signs the canonicalized graph with
+/// `signing_key` first so the emitted JSON carries a provenance signature.
+#[tracing::instrument(skip_all, name = "export_signed_graph_to_json")]
+pub fn export_signed_graph_to_json(graph: &DiGraph<Node, Edge>, signing_key: &ed25519_dalek::SigningKey) -> String {
+    let mut serializable_graph = build_serializable_graph(graph);
+    crate::signing::sign_graph(&mut serializable_graph, signing_key);
     serde_json::to_string_pretty(&serializable_graph).unwrap()
 }
 
+#[tracing::instrument(skip_all, name = "export_graph_to_json_compact")]
 pub fn export_graph_to_json_compact(graph: &DiGraph<Node, Edge>) -> String {
     let mut nodes = Vec::new();
     let mut edges = Vec::new();
@@ -67,6 +82,7 @@ pub fn export_graph_to_json_compact(graph: &DiGraph<Node, Edge>) -> String {
     })).unwrap()
 }
 
+#[tracing::instrument(skip_all, name = "export_stage_filtered_json", fields(stage = ?stage))]
 pub fn export_stage_filtered_json(graph: &DiGraph<Node, Edge>, stage: CorrelationStage) -> String {
     let mut nodes = Vec::new();
     let mut edges = Vec::new();
@@ -7,20 +7,38 @@ pub mod core;
 pub mod graph_cons;
 pub mod serial;
 pub mod graph_to_json;
+pub mod graph_to_arrow;
+pub mod graph_to_csv;
+pub mod signing;
+pub mod genomic_ingest;
+pub mod wards;
+pub mod telemetry;
 
 pub use core::{
     Node, Edge, NodeType, CorrelationType, CorrelationStage,
     NodeMetadata,
 };
-pub use graph_cons::{build_quantum_limit_graph, QuantumLimitGraphBuilder};
+pub use graph_cons::{build_quantum_limit_graph, build_quantum_limit_graph_with_variants, QuantumLimitGraphBuilder};
+pub use genomic_ingest::{VcfVariant, parse_vcf, parse_fasta_accessions};
+pub use wards::{validate_graph, Finding, Severity};
+pub use telemetry::{init_telemetry, otel_enabled, GraphMetrics};
 pub use serial::{
     SerializableNode, SerializableEdge, SerializableGraph,
     SerializableMetadata, GraphMetadata,
 };
 pub use graph_to_json::{
     export_graph_to_json, export_graph_to_json_compact,
-    export_stage_filtered_json,
+    export_stage_filtered_json, export_signed_graph_to_json,
 };
+pub use graph_to_arrow::{
+    export_graph_to_arrow, export_graph_to_parquet,
+    node_schema, edge_schema,
+};
+pub use graph_to_csv::{
+    export_nodes_to_csv, export_edges_to_csv,
+    export_stage_filtered_nodes_csv, export_stage_filtered_edges_csv,
+};
+pub use signing::{Signable, generate_signing_key, sign_graph, verify_graph_signature};
 
 use petgraph::graph::DiGraph;
 
@@ -98,6 +116,152 @@ mod tests {
         assert!(json.contains("Stage1Direct"), "Should filter by stage");
     }
 
+    #[test]
+    fn test_arrow_export() {
+        let graph = build_quantum_limit_graph();
+        let (nodes, edges) = export_graph_to_arrow(&graph);
+        assert_eq!(nodes.num_rows(), graph.node_count());
+        assert_eq!(edges.num_rows(), graph.edge_count());
+    }
+
+    #[test]
+    fn test_parquet_export_round_trip() {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use std::fs::File;
+
+        let graph = build_quantum_limit_graph();
+        let base_path = std::env::temp_dir().join(format!("sarscov2_test_{}", std::process::id()));
+        let base_path = base_path.to_str().unwrap();
+        let nodes_path = format!("{base_path}.nodes.parquet");
+        let edges_path = format!("{base_path}.edges.parquet");
+
+        export_graph_to_parquet(&graph, base_path).unwrap();
+
+        let nodes_reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&nodes_path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let node_rows: usize = nodes_reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(node_rows, graph.node_count());
+
+        let edges_reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&edges_path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let edge_rows: usize = edges_reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(edge_rows, graph.edge_count());
+
+        std::fs::remove_file(&nodes_path).ok();
+        std::fs::remove_file(&edges_path).ok();
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let graph = build_quantum_limit_graph();
+        let nodes_csv = export_nodes_to_csv(&graph);
+        let edges_csv = export_edges_to_csv(&graph);
+        assert!(nodes_csv.starts_with("Id,Label,Type,Stage,QuantumWeight,Confidence\n"));
+        assert!(edges_csv.starts_with("Source,Target,Weight,Type,Stage,Entanglement\n"));
+        assert_eq!(nodes_csv.lines().count() - 1, graph.node_count());
+        assert_eq!(edges_csv.lines().count() - 1, graph.edge_count());
+    }
+
+    #[test]
+    fn test_signed_export_verifies() {
+        let graph = build_quantum_limit_graph();
+        let mut serializable = SerializableGraph {
+            nodes: graph.node_indices().map(|i| SerializableNode::from(&graph[i])).collect(),
+            edges: graph.edge_indices().map(|i| SerializableEdge::from(&graph[i])).collect(),
+            metadata: GraphMetadata {
+                total_nodes: graph.node_count(),
+                total_edges: graph.edge_count(),
+                stages: Vec::new(),
+                quantum_correlation_average: 0.0,
+                generated_at: chrono::Utc::now().to_rfc3339(),
+                signature: None,
+                signer_pubkey: None,
+            },
+        };
+
+        let key = generate_signing_key();
+        sign_graph(&mut serializable, &key);
+        assert!(verify_graph_signature(&serializable));
+
+        serializable.nodes[0].label.push('!');
+        assert!(!verify_graph_signature(&serializable));
+    }
+
+    #[test]
+    fn test_parse_vcf_basic() {
+        let vcf = "##fileformat=VCFv4.2\n\
+                    #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n\
+                    NC_045512.2\t23063\trs1\tA\tT\t.\tPASS\tGENE=S;AA=N501Y;EFFECT=0.8\n";
+        let variants = parse_vcf(vcf);
+        assert_eq!(variants.len(), 1);
+        assert_eq!(variants[0].mutation_label(), "S:N501Y");
+        assert_eq!(variants[0].accession, "rs1");
+        assert_eq!(variants[0].effect_size, 0.8);
+    }
+
+    #[test]
+    fn test_parse_fasta_accessions() {
+        let fasta = ">NC_045512.2 Severe acute respiratory syndrome coronavirus 2\nACGT\n>MN908947.3 another record\nTTTT\n";
+        let accessions = parse_fasta_accessions(fasta);
+        assert_eq!(accessions, vec!["NC_045512.2", "MN908947.3"]);
+    }
+
+    #[test]
+    fn test_genomic_variant_ingestion() {
+        let vcf = "NC_045512.2\t23063\trs1\tA\tT\t.\tPASS\tGENE=S;AA=N501Y;EFFECT=0.8\n";
+        let graph = build_quantum_limit_graph_with_variants(vcf);
+        let variant_node = graph.node_weights().find(|n| n.label == "S:N501Y");
+        assert!(variant_node.is_some(), "ingested variant should appear as a Genomic node");
+        let variant_node = variant_node.unwrap();
+        assert_eq!(variant_node.node_type, NodeType::Genomic);
+        assert_eq!(variant_node.metadata.sources, vec!["rs1".to_string()]);
+        assert_eq!(variant_node.metadata.evidence_count, 1);
+        assert!(
+            graph.node_weights().all(|n| n.label != "Genomic Variants"),
+            "fabricated GenomicVariants placeholder should not coexist with ingested variants"
+        );
+    }
+
+    #[test]
+    fn test_validate_graph_is_clean() {
+        let graph = build_quantum_limit_graph();
+        let findings = validate_graph(&graph);
+        assert!(
+            findings.iter().all(|f| !f.is_error()),
+            "bundled graph should have no validation errors: {findings:?}"
+        );
+    }
+
+    #[test]
+    fn test_otel_disabled_by_default() {
+        std::env::remove_var("SARSCOV2_OTEL");
+        assert!(!otel_enabled(), "telemetry export must stay off unless SARSCOV2_OTEL is set");
+    }
+
+    // The OTLP trace/metric pipelines spawn their batch workers onto a Tokio
+    // runtime, so exercising the enabled path needs one too.
+    #[tokio::test]
+    async fn test_otel_enabled_path_installs_without_panicking() {
+        std::env::set_var("SARSCOV2_OTEL", "1");
+        init_telemetry();
+        let metrics = GraphMetrics::new();
+        metrics.record_file_exported("test");
+        std::env::remove_var("SARSCOV2_OTEL");
+    }
+
+    #[test]
+    fn test_export_signed_graph_to_json() {
+        let graph = build_quantum_limit_graph();
+        let key = generate_signing_key();
+        let json = export_signed_graph_to_json(&graph, &key);
+        let parsed: SerializableGraph = serde_json::from_str(&json).unwrap();
+        assert!(verify_graph_signature(&parsed));
+    }
+
     #[test]
     fn test_graph_stats() {
         let graph = build_quantum_limit_graph();
@@ -3,17 +3,36 @@
 //! Command-line tool for building and exporting the enhanced SARS-CoV-2 correlation graph
 
 use sarscov2_factors::*;
+use base64::Engine;
 use std::fs;
 use std::path::PathBuf;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+// The OTLP trace/metric pipelines spawn their batch-export workers onto a
+// Tokio runtime (see `telemetry::init_telemetry`), so `main` needs one even
+// though everything below it is synchronous.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    init_telemetry();
+    let metrics = GraphMetrics::new();
+
     println!("🧬 SARS-CoV-2 Quantum Limit Graph Builder");
     println!("=========================================\n");
 
-    // Build the graph
-    println!("Building quantum correlation graph...");
-    let graph = build_quantum_limit_graph();
-    
+    // Build the graph, ingesting real variant evidence when a VCF is
+    // available (override the path via SARSCOV2_VARIANTS_VCF).
+    let vcf_path = std::env::var("SARSCOV2_VARIANTS_VCF")
+        .unwrap_or_else(|_| "data/spike_variants.vcf".to_string());
+    let graph = match fs::read_to_string(&vcf_path) {
+        Ok(vcf_content) => {
+            println!("Building quantum correlation graph with variants from: {vcf_path}");
+            build_quantum_limit_graph_with_variants(&vcf_content)
+        }
+        Err(_) => {
+            println!("Building quantum correlation graph (no VCF found at {vcf_path}, using placeholder genomic node)...");
+            build_quantum_limit_graph()
+        }
+    };
+
     // Get statistics
     let stats = get_graph_stats(&graph);
     println!("\n📊 Graph Statistics:");
@@ -23,18 +42,39 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Avg Correlation: {:.3}", stats.avg_correlation);
     println!("  Avg Entanglement: {:.3}", stats.avg_entanglement);
 
-    // Export full graph
+    // Validate the graph before export
+    println!("\n🛡️  Validating graph...");
+    let findings = validate_graph(&graph);
+    let error_count = findings.iter().filter(|f| f.is_error()).count();
+    let warn_count = findings.len() - error_count;
+    for finding in &findings {
+        let icon = if finding.is_error() { "✗" } else { "⚠" };
+        println!("  {icon} {}", finding.message);
+    }
+    println!("  Errors: {error_count}  Warnings: {warn_count}");
+    if error_count > 0 {
+        return Err(format!("{error_count} validation error(s) found; aborting export").into());
+    }
+
+    // Export full graph, signed for provenance
     println!("\n📝 Exporting full graph to JSON...");
-    let json = export_graph_to_json(&graph);
+    let signing_key = generate_signing_key();
+    let json = export_signed_graph_to_json(&graph, &signing_key);
     let output_path = PathBuf::from("sarscov2_quantum_graph_full.json");
     fs::write(&output_path, &json)?;
+    metrics.record_file_exported("json");
     println!("  ✓ Saved to: {}", output_path.display());
+    println!(
+        "  ✓ Signed with ed25519 key (base64): {}",
+        base64::engine::general_purpose::STANDARD.encode(signing_key.verifying_key().to_bytes())
+    );
 
     // Export compact version
     println!("\n📝 Exporting compact graph...");
     let json_compact = export_graph_to_json_compact(&graph);
     let compact_path = PathBuf::from("sarscov2_quantum_graph_compact.json");
     fs::write(&compact_path, &json_compact)?;
+    metrics.record_file_exported("json_compact");
     println!("  ✓ Saved to: {}", compact_path.display());
 
     // Export stage-filtered graphs
@@ -48,12 +88,38 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     ];
 
     for (stage, filename) in stages {
-        let json = export_stage_filtered_json(&graph, stage);
+        let json = export_stage_filtered_json(&graph, stage.clone());
         let path = PathBuf::from(format!("sarscov2_quantum_graph_{}.json", filename));
         fs::write(&path, &json)?;
+        metrics.record_file_exported("json_stage_filtered");
         println!("  ✓ Saved {} to: {}", filename, path.display());
+
+        let nodes_csv = export_stage_filtered_nodes_csv(&graph, stage.clone());
+        let edges_csv = export_stage_filtered_edges_csv(&graph, stage);
+        fs::write(format!("sarscov2_quantum_graph_{}_nodes.csv", filename), &nodes_csv)?;
+        fs::write(format!("sarscov2_quantum_graph_{}_edges.csv", filename), &edges_csv)?;
+        metrics.record_file_exported("csv_stage_filtered");
+        metrics.record_file_exported("csv_stage_filtered");
+        println!("  ✓ Saved {} CSV nodes/edges", filename);
     }
 
+    // Export CSV for Gephi/Cytoscape import
+    println!("\n📝 Exporting full graph to CSV...");
+    let nodes_csv = export_nodes_to_csv(&graph);
+    let edges_csv = export_edges_to_csv(&graph);
+    fs::write("sarscov2_quantum_graph_nodes.csv", &nodes_csv)?;
+    fs::write("sarscov2_quantum_graph_edges.csv", &edges_csv)?;
+    metrics.record_file_exported("csv");
+    metrics.record_file_exported("csv");
+    println!("  ✓ Saved to: sarscov2_quantum_graph_nodes.csv, sarscov2_quantum_graph_edges.csv");
+
+    // Export Arrow/Parquet for analytics (pandas/Polars/DuckDB)
+    println!("\n📝 Exporting full graph to Parquet...");
+    export_graph_to_parquet(&graph, "sarscov2_quantum_graph")?;
+    metrics.record_file_exported("parquet");
+    metrics.record_file_exported("parquet");
+    println!("  ✓ Saved to: sarscov2_quantum_graph.nodes.parquet, sarscov2_quantum_graph.edges.parquet");
+
     // Copy frontend HTML
     println!("\n🌐 Frontend visualization available at: frontend.html");
     println!("   Open this file in a web browser to visualize the graph");
@@ -64,5 +130,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  2. Load sarscov2_quantum_graph_full.json for visualization");
     println!("  3. Use stage filters to explore different correlation levels");
 
+    // Flush any batched spans before the process exits. Only the tracer
+    // provider is shut down here; the metrics pipeline is not flushed.
+    if otel_enabled() {
+        opentelemetry::global::shutdown_tracer_provider();
+    }
+
     Ok(())
 }
@@ -47,6 +47,10 @@ pub struct GraphMetadata {
     pub stages: Vec<String>,
     pub quantum_correlation_average: f32,
     pub generated_at: String,
+    /// Base64 ed25519 signature over the canonicalized graph, set by `sign_graph`.
+    pub signature: Option<String>,
+    /// Base64 ed25519 public key that produced `signature`.
+    pub signer_pubkey: Option<String>,
 }
 
 impl From<&Node> for SerializableNode {
@@ -0,0 +1,120 @@
+//! Optional OpenTelemetry instrumentation for graph construction and export.
+//!
+//! Quiet by default: only a local `tracing` subscriber is installed. Set
+//! `SARSCOV2_OTEL=1` to additionally export spans AND metrics via OTLP
+//! (respecting the standard `OTEL_EXPORTER_OTLP_ENDPOINT`).
+//!
+//! The OTLP batch span/metric exporters spawn their background workers onto
+//! a Tokio runtime, so `init_telemetry` must be called from within one —
+//! `main` runs under `#[tokio::main]` for exactly this reason.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::runtime::Tokio;
+
+use crate::core::CorrelationStage;
+
+static METER: OnceLock<Meter> = OnceLock::new();
+
+/// Whether OTEL export is enabled via the `SARSCOV2_OTEL` env var.
+pub fn otel_enabled() -> bool {
+    std::env::var("SARSCOV2_OTEL")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Install a `tracing` subscriber, wiring OTLP trace and metric pipelines in
+/// when `SARSCOV2_OTEL` is set. Must run on a Tokio runtime (the OTLP batch
+/// exporters spawn onto it); safe to call once at process start.
+pub fn init_telemetry() {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let filter = tracing_subscriber::EnvFilter::from_default_env();
+    let fmt_layer = tracing_subscriber::fmt::layer();
+
+    if otel_enabled() {
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+            .install_batch(Tokio)
+            .expect("failed to install OTLP trace pipeline");
+
+        let meter_provider = opentelemetry_otlp::new_pipeline()
+            .metrics(Tokio)
+            .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+            .build()
+            .expect("failed to install OTLP metrics pipeline");
+        opentelemetry::global::set_meter_provider(meter_provider);
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(tracing_opentelemetry::layer().with_tracer(tracer))
+            .with(fmt_layer)
+            .try_init()
+            .ok();
+    } else {
+        tracing_subscriber::registry().with(filter).with(fmt_layer).try_init().ok();
+    }
+}
+
+fn meter() -> &'static Meter {
+    METER.get_or_init(|| opentelemetry::global::meter("sarscov2_factors"))
+}
+
+/// Counters/histograms for graph construction and export. The instruments
+/// are always created; they only leave the process once `init_telemetry`
+/// has wired up a real OTLP pipeline via `SARSCOV2_OTEL`.
+pub struct GraphMetrics {
+    nodes_added: Counter<u64>,
+    edges_added: Counter<u64>,
+    stage_build_duration: Histogram<f64>,
+    files_exported: Counter<u64>,
+}
+
+impl GraphMetrics {
+    pub fn new() -> Self {
+        let meter = meter();
+        Self {
+            nodes_added: meter
+                .u64_counter("sarscov2.nodes_added")
+                .with_description("Nodes added per correlation stage")
+                .init(),
+            edges_added: meter
+                .u64_counter("sarscov2.edges_added")
+                .with_description("Edges added per correlation stage")
+                .init(),
+            stage_build_duration: meter
+                .f64_histogram("sarscov2.stage_build_duration_seconds")
+                .with_description("Wall time spent constructing each correlation stage")
+                .init(),
+            files_exported: meter
+                .u64_counter("sarscov2.files_exported")
+                .with_description("Files written by the exporters")
+                .init(),
+        }
+    }
+
+    pub fn record_stage_nodes(&self, stage: &CorrelationStage, count: usize, duration: Duration) {
+        let attrs = [KeyValue::new("stage", format!("{stage:?}"))];
+        self.nodes_added.add(count as u64, &attrs);
+        self.stage_build_duration.record(duration.as_secs_f64(), &attrs);
+    }
+
+    pub fn record_stage_edges(&self, stage: &CorrelationStage, count: usize) {
+        self.edges_added.add(count as u64, &[KeyValue::new("stage", format!("{stage:?}"))]);
+    }
+
+    pub fn record_file_exported(&self, format: &str) {
+        self.files_exported.add(1, &[KeyValue::new("format", format.to_string())]);
+    }
+}
+
+impl Default for GraphMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
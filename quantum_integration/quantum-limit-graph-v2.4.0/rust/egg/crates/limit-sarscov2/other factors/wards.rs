@@ -0,0 +1,145 @@
+//! Structural and domain validation ("warding") over a built graph, run
+//! before export so malformed correlation data is caught early.
+
+use std::collections::HashSet;
+
+use petgraph::graph::DiGraph;
+
+use crate::core::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+/// Run every ward over the graph and return all findings in encounter order.
+pub fn validate_graph(graph: &DiGraph<Node, Edge>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    findings.extend(ward_dangling_edges(graph));
+    findings.extend(ward_score_ranges(graph));
+    findings.extend(ward_quantum_entangled_stage(graph));
+    findings.extend(ward_duplicate_edges(graph));
+    findings
+}
+
+fn in_unit_range(value: f32) -> bool {
+    (0.0..=1.0).contains(&value)
+}
+
+/// Every `Edge.from`/`Edge.to` must resolve to an existing node `numeric_id`.
+fn ward_dangling_edges(graph: &DiGraph<Node, Edge>) -> Vec<Finding> {
+    let numeric_ids: HashSet<usize> = graph.node_weights().map(|n| n.numeric_id).collect();
+    let mut findings = Vec::new();
+
+    for edge in graph.edge_weights() {
+        if !numeric_ids.contains(&edge.from) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!("edge {} references missing source node {}", edge.id, edge.from),
+            });
+        }
+        if !numeric_ids.contains(&edge.to) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!("edge {} references missing target node {}", edge.id, edge.to),
+            });
+        }
+    }
+
+    findings
+}
+
+/// `correlation_strength`, `quantum_weight`, `quantum_entanglement`, and
+/// `confidence_score` must all lie in `[0.0, 1.0]`.
+fn ward_score_ranges(graph: &DiGraph<Node, Edge>) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    for node in graph.node_weights() {
+        if !in_unit_range(node.quantum_weight) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!("node {} quantum_weight {} is outside [0.0, 1.0]", node.label, node.quantum_weight),
+            });
+        }
+        if !in_unit_range(node.metadata.confidence_score) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!(
+                    "node {} confidence_score {} is outside [0.0, 1.0]",
+                    node.label, node.metadata.confidence_score
+                ),
+            });
+        }
+    }
+
+    for edge in graph.edge_weights() {
+        if !in_unit_range(edge.correlation_strength) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!(
+                    "edge {} correlation_strength {} is outside [0.0, 1.0]",
+                    edge.id, edge.correlation_strength
+                ),
+            });
+        }
+        if !in_unit_range(edge.quantum_entanglement) {
+            findings.push(Finding {
+                severity: Severity::Error,
+                message: format!(
+                    "edge {} quantum_entanglement {} is outside [0.0, 1.0]",
+                    edge.id, edge.quantum_entanglement
+                ),
+            });
+        }
+    }
+
+    findings
+}
+
+/// `QuantumEntangled` edges should only appear in `Stage5Quantum`.
+fn ward_quantum_entangled_stage(graph: &DiGraph<Node, Edge>) -> Vec<Finding> {
+    graph
+        .edge_weights()
+        .filter(|edge| {
+            matches!(edge.correlation_type, CorrelationType::QuantumEntangled)
+                && !matches!(edge.stage, CorrelationStage::Stage5Quantum)
+        })
+        .map(|edge| Finding {
+            severity: Severity::Warn,
+            message: format!(
+                "edge {} is QuantumEntangled but tagged {:?} instead of Stage5Quantum",
+                edge.id, edge.stage
+            ),
+        })
+        .collect()
+}
+
+/// No duplicate edges between the same ordered pair.
+fn ward_duplicate_edges(graph: &DiGraph<Node, Edge>) -> Vec<Finding> {
+    let mut seen = HashSet::new();
+    let mut findings = Vec::new();
+
+    for edge in graph.edge_weights() {
+        if !seen.insert((edge.from, edge.to)) {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                message: format!("duplicate edge between node {} and node {}", edge.from, edge.to),
+            });
+        }
+    }
+
+    findings
+}